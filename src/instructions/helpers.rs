@@ -1,28 +1,166 @@
+use crate::state::{EscrowV0, ESCROW_DISCRIMINATOR, ESCROW_VERSION, SEED_PREFIX};
 use crate::Escrow;
 use core::mem::size_of;
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use sha2::{Digest, Sha256};
 
+/// Marker appended to every PDA derivation, matching Solana's
+/// `create_program_address`.
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+/// Maximum number of seeds `create_program_address` accepts.
+const MAX_SEEDS: usize = 16;
+
+/// Maximum length, in bytes, of a single seed.
+const MAX_SEED_LEN: usize = 32;
+
+/// Derives the address for `seeds` under `program_id` exactly as
+/// `solana_program::pubkey::Pubkey::create_program_address` does: the seeds,
+/// the program id, and the `PDA_MARKER` are SHA-256 hashed together, and the
+/// result is accepted only if it does *not* land on the ed25519 curve (a
+/// valid PDA must be unable to double as a signer's public key).
+fn create_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<Pubkey, ProgramError> {
+    if seeds.len() > MAX_SEEDS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    for seed in seeds {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::InvalidSeeds);
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id.as_ref());
+    hasher.update(PDA_MARKER);
+    let hash = hasher.finalize();
+
+    if is_on_curve(&hash) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut address = [0u8; 32];
+    address.copy_from_slice(&hash);
+    Ok(address)
+}
+
+/// Returns `true` if `bytes` decompress to a valid ed25519 curve point.
+fn is_on_curve(bytes: &[u8]) -> bool {
+    match <[u8; 32]>::try_from(bytes) {
+        Ok(bytes) => CompressedEdwardsY(bytes).decompress().is_some(),
+        Err(_) => false,
+    }
+}
+
+// The `load`/`load_mut`/`load_v0` loaders below all go through
+// `bytemuck::try_from_bytes[_mut]`, which validates both the length and the
+// alignment of the input before handing back a reference — there's no UB
+// risk even if the account data pointer isn't 8-byte aligned.
 impl Escrow {
     /// The expected size of the `Escrow` account data in bytes.
     ///
     /// This is calculated by summing the sizes of all fields in the `Escrow` struct:
+    /// - `discriminator`: [u8; 8] (8 bytes)
+    /// - `version`: u8 (1 byte)
     /// - `seed`: u64 (8 bytes)
     /// - `maker`: Pubkey (32 bytes)
     /// - `mint_a`: Pubkey (32 bytes)
     /// - `mint_b`: Pubkey (32 bytes)
     /// - `receive`: u64 (8 bytes)
+    /// - `deposited`: u64 (8 bytes)
+    /// - `filled`: u64 (8 bytes)
+    /// - `expiry`: i64 (8 bytes)
     /// - `bump`: [u8; 1] (1 byte)
-    /// Total: 113 bytes.
+    /// Total: 146 bytes. `Escrow` is `repr(packed)`, so this always equals
+    /// `size_of::<Escrow>()` exactly, with no alignment padding.
     ///
     /// This constant is used to validate that the account data passed to the program
     /// matches the expected layout structure.
-    pub const LEN: usize = size_of::<u64>()
+    pub const LEN: usize = size_of::<[u8; 8]>()
+        + size_of::<u8>()
+        + size_of::<u64>()
         + size_of::<Pubkey>()
         + size_of::<Pubkey>()
         + size_of::<Pubkey>()
         + size_of::<u64>()
+        + size_of::<u64>()
+        + size_of::<u64>()
+        + size_of::<i64>()
         + size_of::<[u8; 1]>();
 
+    /// The size, in bytes, of an `Escrow` account created before partial
+    /// fills and expiry existed (see `EscrowV0`, also `repr(packed)`). Still
+    /// accepted by `load_v0` so those accounts keep working without a
+    /// migration.
+    pub const LEN_V0: usize = size_of::<[u8; 8]>()
+        + size_of::<u64>()
+        + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + size_of::<u64>()
+        + size_of::<[u8; 1]>();
+
+    /// Loads a mutable reference to a freshly created `Escrow` account, writing
+    /// the discriminator as it does so.
+    ///
+    /// This is the loader to use right after account creation: the account data
+    /// is all zeros at that point, so the discriminator check `load`/`load_mut`
+    /// perform would fail. `load_init` instead tolerates the all-zero initial
+    /// state, stamps the discriminator, and hands back a mutable reference so
+    /// the caller can populate the rest of the fields (e.g. via `set_inner`).
+    ///
+    /// # Arguments
+    /// * `bytes` - The mutable raw account data from the `AccountInfo`.
+    #[inline(always)]
+    pub fn load_init(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        let escrow: &mut Self =
+            bytemuck::try_from_bytes_mut(bytes).map_err(|_| ProgramError::InvalidAccountData)?;
+        escrow.discriminator = ESCROW_DISCRIMINATOR;
+        escrow.version = ESCROW_VERSION;
+
+        Ok(escrow)
+    }
+
+    /// Reads a legacy, pre-versioning escrow account (`Escrow::LEN_V0`
+    /// bytes, implicitly version 0). Returns an owned `Escrow` with
+    /// `deposited`, `filled` and `expiry` defaulted to zero, so callers can
+    /// migrate it onto the current layout by reallocating the account to
+    /// `Escrow::LEN` and writing the result back with `load_init`/`set_inner`.
+    ///
+    /// `load`/`load_mut` can't dispatch to this automatically: a `Escrow::LEN_V0`
+    /// account is genuinely too short to hold the current layout, so there's no
+    /// in-place reference to hand back without first growing the account data.
+    /// Callers that might encounter pre-migration accounts must check the
+    /// length themselves and call `load_v0` explicitly before ever calling
+    /// `load`/`load_mut` on them; see the `migrates_a_v0_account` test below
+    /// for the full reallocate-then-`load_init` sequence.
+    pub fn load_v0(bytes: &[u8]) -> Result<Self, ProgramError> {
+        let legacy: &EscrowV0 =
+            bytemuck::try_from_bytes(bytes).map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if legacy.discriminator != ESCROW_DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Escrow {
+            discriminator: ESCROW_DISCRIMINATOR,
+            version: ESCROW_VERSION,
+            seed: legacy.seed,
+            maker: legacy.maker,
+            mint_a: legacy.mint_a,
+            mint_b: legacy.mint_b,
+            receive: legacy.receive,
+            deposited: 0,
+            filled: 0,
+            expiry: 0,
+            bump: legacy.bump,
+        })
+    }
+
     /// Loads a mutable reference to the `Escrow` struct from a raw byte slice.
     ///
     /// This function performs a "zero-copy" deserialization. Instead of copying
@@ -34,43 +172,46 @@ impl Escrow {
     ///
     /// # Returns
     /// * `Ok(&mut Self)` - A mutable reference to the `Escrow` struct if successful.
-    /// * `Err(ProgramError)` - `InvalidAccountData` if the byte slice length is incorrect.
+    /// * `Err(ProgramError)` - `InvalidAccountData` if the byte slice length is incorrect,
+    ///   the discriminator doesn't match `ESCROW_DISCRIMINATOR`, or `version` isn't
+    ///   `ESCROW_VERSION`.
+    ///
+    /// Only accepts `Escrow::LEN`-sized, current-version account data. A
+    /// legacy `Escrow::LEN_V0`-sized account is a different, shorter layout
+    /// and must never be passed here directly; migrate it first via
+    /// `load_v0`, reallocating the account to `Escrow::LEN` before writing
+    /// the migrated data back with `load_init`/`set_inner`.
     #[inline(always)]
     pub fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
-        // Validate that the account data has exactly the expected size.
-        // This prevents reading/writing outside allocated memory or processing corrupt data.
-        if bytes.len() != Escrow::LEN {
+        let escrow: &mut Self =
+            bytemuck::try_from_bytes_mut(bytes).map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if escrow.discriminator != ESCROW_DISCRIMINATOR || escrow.version != ESCROW_VERSION {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // SAFETY:
-        // 1. We validated the length above, ensuring we have enough bytes.
-        // 2. `Escrow` is `#[repr(C)]`, ensuring a predictable memory layout.
-        // 3. We use `transmute` to cast the pointer:
-        //    - `bytes.as_mut_ptr()` gives us a `*mut u8` (pointer to the first byte).
-        //    - We cast it to `*mut Self` (pointer to an Escrow struct).
-        //    - We dereference it (`*`) and borrow it mutably (`&mut`).
-        // This is safe assuming the alignment is correct (which typically is for u8 arrays on Solana).
-        Ok(unsafe { &mut *core::mem::transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
+        Ok(escrow)
     }
 
     /// Loads an immutable reference to the `Escrow` struct from a raw byte slice.
     ///
-    /// Similar to `load_mut`, but for read-only access.
+    /// Similar to `load_mut`, but for read-only access. As with `load_mut`,
+    /// only `Escrow::LEN`-sized, current-version account data is accepted
+    /// (mismatched `version` is rejected too); a legacy `Escrow::LEN_V0`-sized
+    /// account must go through `load_v0` instead.
     ///
     /// # Arguments
     /// * `bytes` - The immutable raw account data.
     #[inline(always)]
     pub fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
-        // Validation: Ensure the data length matches the struct definition.
-        if bytes.len() != Escrow::LEN {
+        let escrow: &Self =
+            bytemuck::try_from_bytes(bytes).map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if escrow.discriminator != ESCROW_DISCRIMINATOR || escrow.version != ESCROW_VERSION {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // SAFETY:
-        // Casts the `*const u8` pointer to a `*const Self` pointer.
-        // Returns an immutable reference.
-        Ok(unsafe { &*core::mem::transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+        Ok(escrow)
     }
 
     /// Sets the `seed` field.
@@ -120,6 +261,7 @@ impl Escrow {
     ///
     /// This is a convenience method to initialize the entire struct in one call,
     /// typically used in the instruction that initializes the account (e.g., `Make` or `Deposit`).
+    /// `deposited` and `filled` always start at zero for a freshly made escrow.
     #[inline(always)]
     pub fn set_inner(
         &mut self,
@@ -128,6 +270,7 @@ impl Escrow {
         mint_a: Pubkey,
         mint_b: Pubkey,
         receive: u64,
+        expiry: i64,
         bump: [u8; 1],
     ) {
         self.seed = seed;
@@ -135,6 +278,394 @@ impl Escrow {
         self.mint_a = mint_a;
         self.mint_b = mint_b;
         self.receive = receive;
+        self.deposited = 0;
+        self.filled = 0;
+        self.expiry = expiry;
         self.bump = bump;
     }
+
+    /// Sets the `deposited` field.
+    /// Stores the amount of token A the maker has escrowed.
+    #[inline(always)]
+    pub fn set_deposited(&mut self, deposited: u64) {
+        self.deposited = deposited;
+    }
+
+    /// Records that `amount` of token B has just been received toward this
+    /// escrow's `receive` target, enabling incremental/partial fills instead
+    /// of requiring one atomic take.
+    ///
+    /// Uses checked arithmetic and rejects any fill that would push `filled`
+    /// past `receive`.
+    #[inline(always)]
+    pub fn record_fill(&mut self, amount: u64) -> Result<(), ProgramError> {
+        let filled = self
+            .filled
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // `Escrow` is `repr(packed)`, so `receive` is copied to a local
+        // before comparing rather than referenced in place.
+        let receive = self.receive;
+        if filled > receive {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.filled = filled;
+
+        Ok(())
+    }
+
+    /// Returns `true` once `now` reaches this escrow's `expiry` (the `expiry`
+    /// timestamp itself already counts as expired). An `expiry` of `0` means
+    /// the offer never lapses.
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        // Copied to a local for the same reason as in `record_fill`.
+        let expiry = self.expiry;
+        expiry != 0 && now >= expiry
+    }
+
+    /// Builds the seed list used to derive and sign for this escrow's PDA.
+    ///
+    /// `seed_bytes` must be `self.seed.to_le_bytes()`, kept alive by the
+    /// caller so the returned slices can borrow from it:
+    /// ```ignore
+    /// let seed_bytes = escrow.seed.to_le_bytes();
+    /// let seeds = escrow.seeds(&seed_bytes);
+    /// ```
+    #[inline(always)]
+    pub fn seeds<'a>(&'a self, seed_bytes: &'a [u8; 8]) -> [&'a [u8]; 4] {
+        [
+            SEED_PREFIX,
+            self.maker.as_ref(),
+            seed_bytes,
+            self.bump.as_ref(),
+        ]
+    }
+
+    /// Finds the canonical (highest valid) bump for an escrow PDA derived
+    /// from `maker` and `seed` under `program_id`.
+    ///
+    /// Mirrors `Pubkey::find_program_address`: it tries bumps from 255 down
+    /// to 0 and returns the first one whose derived address falls off the
+    /// ed25519 curve. Returns `ProgramError::InvalidSeeds` if none do, which
+    /// in practice never happens.
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        maker: &Pubkey,
+        seed: u64,
+    ) -> Result<(Pubkey, u8), ProgramError> {
+        let seed_bytes = seed.to_le_bytes();
+
+        for bump in (0..=u8::MAX).rev() {
+            let bump_bytes = [bump];
+            let seeds: [&[u8]; 4] = [SEED_PREFIX, maker.as_ref(), &seed_bytes, &bump_bytes];
+
+            if let Ok(address) = create_program_address(&seeds, program_id) {
+                return Ok((address, bump));
+            }
+        }
+
+        Err(ProgramError::InvalidSeeds)
+    }
+
+    /// Recomputes this escrow's PDA from its stored `seed`, `maker`, and
+    /// `bump`, and checks it matches `key`.
+    ///
+    /// Lets an instruction assert that the account passed in truly is the
+    /// PDA this escrow claims to be, instead of trusting `key` outright.
+    pub fn verify(&self, program_id: &Pubkey, key: &Pubkey) -> Result<(), ProgramError> {
+        let seed_bytes = self.seed.to_le_bytes();
+        let seeds = self.seeds(&seed_bytes);
+
+        let address = create_program_address(&seeds, program_id)?;
+
+        if address != *key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_escrow() -> Escrow {
+        let mut bytes = [0u8; Escrow::LEN];
+        let escrow = Escrow::load_init(&mut bytes).unwrap();
+        escrow.set_inner(42, [1u8; 32], [2u8; 32], [3u8; 32], 1_000, 0, [255]);
+        *escrow
+    }
+
+    #[test]
+    fn record_fill_accumulates_partial_fills() {
+        let mut escrow = new_escrow();
+        escrow.record_fill(400).unwrap();
+        escrow.record_fill(600).unwrap();
+
+        let filled = escrow.filled;
+        assert_eq!(filled, 1_000);
+    }
+
+    #[test]
+    fn record_fill_rejects_amount_past_receive() {
+        let mut escrow = new_escrow();
+        assert_eq!(
+            escrow.record_fill(1_001),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn record_fill_rejects_overflow() {
+        let mut escrow = new_escrow();
+        escrow.filled = u64::MAX;
+        assert_eq!(
+            escrow.record_fill(1),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn is_expired_treats_zero_expiry_as_never() {
+        let mut escrow = new_escrow();
+        escrow.expiry = 0;
+        assert!(!escrow.is_expired(i64::MAX));
+    }
+
+    #[test]
+    fn is_expired_at_the_expiry_boundary() {
+        let mut escrow = new_escrow();
+        escrow.expiry = 1_000;
+        assert!(!escrow.is_expired(999));
+        assert!(escrow.is_expired(1_000));
+        assert!(escrow.is_expired(1_001));
+    }
+
+    #[test]
+    fn load_mut_rejects_a_stale_version() {
+        let mut bytes = [0u8; Escrow::LEN];
+        Escrow::load_init(&mut bytes).unwrap();
+        bytes[8] = ESCROW_VERSION.wrapping_add(1);
+
+        assert!(matches!(
+            Escrow::load_mut(&mut bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn load_rejects_a_stale_version() {
+        let mut bytes = [0u8; Escrow::LEN];
+        Escrow::load_init(&mut bytes).unwrap();
+        bytes[8] = ESCROW_VERSION.wrapping_add(1);
+
+        assert!(matches!(
+            Escrow::load(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn find_program_address_round_trips_through_verify() {
+        let program_id = [9u8; 32];
+        let mut escrow = new_escrow();
+        escrow.maker = [1u8; 32];
+        escrow.seed = 7;
+
+        let (address, bump) = Escrow::find_program_address(&program_id, &escrow.maker, 7).unwrap();
+        escrow.bump = [bump];
+
+        escrow.verify(&program_id, &address).unwrap();
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_seed() {
+        let program_id = [9u8; 32];
+        let mut escrow = new_escrow();
+        escrow.maker = [1u8; 32];
+        escrow.seed = 7;
+
+        let (address, bump) = Escrow::find_program_address(&program_id, &escrow.maker, 7).unwrap();
+        escrow.bump = [bump];
+        escrow.seed = 8;
+
+        assert_eq!(
+            escrow.verify(&program_id, &address),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_a_tampered_bump() {
+        let program_id = [9u8; 32];
+        let mut escrow = new_escrow();
+        escrow.maker = [1u8; 32];
+        escrow.seed = 7;
+
+        let (address, bump) = Escrow::find_program_address(&program_id, &escrow.maker, 7).unwrap();
+        escrow.bump = [bump.wrapping_sub(1)];
+
+        assert_eq!(
+            escrow.verify(&program_id, &address),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_a_mismatched_program_id() {
+        let program_id = [9u8; 32];
+        let other_program_id = [10u8; 32];
+        let mut escrow = new_escrow();
+        escrow.maker = [1u8; 32];
+        escrow.seed = 7;
+
+        let (address, bump) = Escrow::find_program_address(&program_id, &escrow.maker, 7).unwrap();
+        escrow.bump = [bump];
+
+        assert_eq!(
+            escrow.verify(&other_program_id, &address),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn create_program_address_rejects_too_many_seeds() {
+        let program_id = [9u8; 32];
+        let seed: &[u8] = b"s";
+        let seeds: [&[u8]; MAX_SEEDS + 1] = [seed; MAX_SEEDS + 1];
+
+        assert_eq!(
+            create_program_address(&seeds, &program_id),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn create_program_address_rejects_an_oversized_seed() {
+        let program_id = [9u8; 32];
+        let oversized = [0u8; MAX_SEED_LEN + 1];
+        let seeds: [&[u8]; 1] = [&oversized];
+
+        assert_eq!(
+            create_program_address(&seeds, &program_id),
+            Err(ProgramError::InvalidSeeds)
+        );
+    }
+
+    #[test]
+    fn load_init_tolerates_all_zero_account_data() {
+        let mut bytes = [0u8; Escrow::LEN];
+        let escrow = Escrow::load_init(&mut bytes).unwrap();
+
+        assert_eq!(escrow.discriminator, ESCROW_DISCRIMINATOR);
+        let version = escrow.version;
+        assert_eq!(version, ESCROW_VERSION);
+    }
+
+    #[test]
+    fn load_mut_rejects_a_mismatched_discriminator() {
+        let mut bytes = [0u8; Escrow::LEN];
+        Escrow::load_init(&mut bytes).unwrap();
+        bytes[0] = b'X';
+
+        assert!(matches!(
+            Escrow::load_mut(&mut bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn load_rejects_a_mismatched_discriminator() {
+        let mut bytes = [0u8; Escrow::LEN];
+        Escrow::load_init(&mut bytes).unwrap();
+        bytes[0] = b'X';
+
+        assert!(matches!(
+            Escrow::load(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn load_rejects_an_all_zero_account() {
+        let bytes = [0u8; Escrow::LEN];
+
+        assert!(matches!(
+            Escrow::load(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn load_rejects_a_short_buffer() {
+        let bytes = [0u8; Escrow::LEN - 1];
+
+        assert!(matches!(
+            Escrow::load(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn load_mut_rejects_a_short_buffer() {
+        let mut bytes = [0u8; Escrow::LEN - 1];
+
+        assert!(matches!(
+            Escrow::load_mut(&mut bytes),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+
+    #[test]
+    fn migrates_a_v0_account() {
+        let mut v0_bytes = [0u8; Escrow::LEN_V0];
+        {
+            let legacy: &mut EscrowV0 = bytemuck::try_from_bytes_mut(&mut v0_bytes).unwrap();
+            legacy.discriminator = ESCROW_DISCRIMINATOR;
+            legacy.seed = 7;
+            legacy.maker = [1u8; 32];
+            legacy.mint_a = [2u8; 32];
+            legacy.mint_b = [3u8; 32];
+            legacy.receive = 500;
+            legacy.bump = [254];
+        }
+
+        let migrated = Escrow::load_v0(&v0_bytes).unwrap();
+        let (migrated_seed, migrated_receive) = (migrated.seed, migrated.receive);
+
+        // Simulates the account being reallocated to `Escrow::LEN` and the
+        // migrated state being written back via `load_init`/`set_inner`.
+        let mut bytes = [0u8; Escrow::LEN];
+        let escrow = Escrow::load_init(&mut bytes).unwrap();
+        escrow.set_inner(
+            migrated_seed,
+            migrated.maker,
+            migrated.mint_a,
+            migrated.mint_b,
+            migrated_receive,
+            migrated.expiry,
+            migrated.bump,
+        );
+
+        let escrow = Escrow::load_mut(&mut bytes).unwrap();
+        let (version, seed, receive, deposited, filled) = (
+            escrow.version,
+            escrow.seed,
+            escrow.receive,
+            escrow.deposited,
+            escrow.filled,
+        );
+
+        assert_eq!(version, ESCROW_VERSION);
+        assert_eq!(seed, 7);
+        assert_eq!(escrow.maker, [1u8; 32]);
+        assert_eq!(receive, 500);
+        assert_eq!(deposited, 0);
+        assert_eq!(filled, 0);
+        assert_eq!(escrow.bump, [254]);
+    }
 }
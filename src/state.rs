@@ -1,12 +1,176 @@
+use bytemuck::{Pod, Zeroable};
 use core::mem::size_of;
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
-#[repr(C)]
+/// Account discriminator identifying an `Escrow` account, stored as the
+/// leading 8 bytes of the account data. Prevents another account type of
+/// the same size (owned by this program) from being misread as an `Escrow`.
+pub const ESCROW_DISCRIMINATOR: [u8; 8] = *b"ESCROW__";
+
+/// First seed of every `Escrow` PDA, so derivations can't collide with other
+/// PDAs this program derives.
+pub const SEED_PREFIX: &[u8] = b"escrow";
+
+/// Current `Escrow::version`. Bump this whenever the layout changes again.
+pub const ESCROW_VERSION: u8 = 1;
+
+// `packed` so the struct has no inter-field or trailing alignment padding:
+// with `version`/`bump` single-byte fields alongside 8-byte-aligned `u64`s,
+// a plain `#[repr(C)]` layout would round up to the next multiple of 8 and
+// leave uninitialized padding bytes, which `Pod` correctly refuses to derive
+// for. Packed also keeps `size_of::<Escrow>()` exactly equal to the sum of
+// its fields, matching the fixed on-chain account size bit for bit.
+//
+// `version` comes right after `discriminator` (rather than at the end) so
+// it's a cheap leading tag: a caller can check `bytes[8]` before touching
+// the rest of the account, without needing the full `Escrow::LEN`-sized
+// layout to be present.
+//
+// Multi-byte fields (`u64`/`i64`) must be copied to a local before comparing
+// or borrowing (e.g. `let seed = self.seed;`), since Rust won't form a
+// reference directly to a field that isn't guaranteed aligned.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
 pub struct Escrow {
-    pub seed: u64,      // Random seed for PDA derivation
-    pub maker: Pubkey,  // Creator of the escrow
-    pub mint_a: Pubkey, // Token being deposited
-    pub mint_b: Pubkey, // Token being requested
-    pub receive: u64,   // Amount of token B wanted
-    pub bump: [u8; 1],  // PDA bump seed
+    pub discriminator: [u8; 8], // Account type tag, always `ESCROW_DISCRIMINATOR`
+    pub version: u8,   // Layout version, always `ESCROW_VERSION` for new accounts
+    pub seed: u64,              // Random seed for PDA derivation
+    pub maker: Pubkey,          // Creator of the escrow
+    pub mint_a: Pubkey,         // Token being deposited
+    pub mint_b: Pubkey,         // Token being requested
+    pub receive: u64,           // Amount of token B wanted
+    pub deposited: u64,         // Amount of token A escrowed
+    pub filled: u64,            // Amount of token B received so far
+    pub expiry: i64, // Unix timestamp from which the maker can reclaim funds; 0 = no expiry
+    pub bump: [u8; 1], // PDA bump seed
+}
+
+/// Legacy (pre-versioning) on-chain layout, fixed at `Escrow::LEN_V0` bytes.
+/// Accounts created before partial fills and expiry existed have no
+/// `version`/`deposited`/`filled`/`expiry` fields and are implicitly version 0.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct EscrowV0 {
+    pub discriminator: [u8; 8],
+    pub seed: u64,
+    pub maker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub receive: u64,
+    pub bump: [u8; 1],
+}
+
+/// Stable, little-endian wire format for the logical `Escrow` fields, used by
+/// off-chain clients and indexers that can't rely on the in-memory
+/// `repr(C, packed)` layout (native endianness, no self-describing schema) to
+/// decode the account.
+///
+/// The internal `discriminator` is intentionally left out: it's an on-chain
+/// implementation detail, not part of the escrow's logical state.
+#[cfg(feature = "borsh")]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+struct EscrowBorsh {
+    version: u8,
+    seed: u64,
+    maker: Pubkey,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    receive: u64,
+    deposited: u64,
+    filled: u64,
+    expiry: i64,
+    bump: [u8; 1],
+}
+
+#[cfg(feature = "borsh")]
+impl Escrow {
+    /// Encodes the logical fields of this escrow into their Borsh wire
+    /// format.
+    pub fn serialize(&self) -> Result<Vec<u8>, borsh::io::Error> {
+        borsh::to_vec(&EscrowBorsh {
+            version: self.version,
+            seed: self.seed,
+            maker: self.maker,
+            mint_a: self.mint_a,
+            mint_b: self.mint_b,
+            receive: self.receive,
+            deposited: self.deposited,
+            filled: self.filled,
+            expiry: self.expiry,
+            bump: self.bump,
+        })
+    }
+
+    /// Decodes `bytes` produced by `serialize` back into an `Escrow`,
+    /// stamping the account discriminator since the wire format doesn't
+    /// carry one.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, borsh::io::Error> {
+        let decoded = <EscrowBorsh as borsh::BorshDeserialize>::try_from_slice(bytes)?;
+
+        Ok(Escrow {
+            discriminator: ESCROW_DISCRIMINATOR,
+            version: decoded.version,
+            seed: decoded.seed,
+            maker: decoded.maker,
+            mint_a: decoded.mint_a,
+            mint_b: decoded.mint_b,
+            receive: decoded.receive,
+            deposited: decoded.deposited,
+            filled: decoded.filled,
+            expiry: decoded.expiry,
+            bump: decoded.bump,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "borsh"))]
+mod borsh_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_borsh() {
+        let escrow = Escrow {
+            discriminator: ESCROW_DISCRIMINATOR,
+            version: ESCROW_VERSION,
+            seed: 42,
+            maker: [1u8; 32],
+            mint_a: [2u8; 32],
+            mint_b: [3u8; 32],
+            receive: 1_000_000,
+            deposited: 1_000_000,
+            filled: 250_000,
+            expiry: 9_999,
+            bump: [255],
+        };
+
+        let bytes = escrow.serialize().unwrap();
+
+        // 1 (version) + 8 (seed) + 32 * 3 (maker/mint_a/mint_b) + 8 (receive)
+        // + 8 (deposited) + 8 (filled) + 8 (expiry) + 1 (bump) = 138 bytes,
+        // and the field order matches the struct definition above.
+        assert_eq!(bytes.len(), 138);
+
+        let decoded = Escrow::deserialize(&bytes).unwrap();
+
+        // `Escrow` is `repr(packed)`, so multi-byte fields are copied to
+        // locals before comparing rather than referenced in place.
+        let (decoded_version, decoded_seed, decoded_receive) =
+            (decoded.version, decoded.seed, decoded.receive);
+        let (decoded_deposited, decoded_filled, decoded_expiry) =
+            (decoded.deposited, decoded.filled, decoded.expiry);
+        let (version, seed, receive) = (escrow.version, escrow.seed, escrow.receive);
+        let (deposited, filled, expiry) = (escrow.deposited, escrow.filled, escrow.expiry);
+
+        assert_eq!(decoded.discriminator, ESCROW_DISCRIMINATOR);
+        assert_eq!(decoded_version, version);
+        assert_eq!(decoded_seed, seed);
+        assert_eq!(decoded.maker, escrow.maker);
+        assert_eq!(decoded.mint_a, escrow.mint_a);
+        assert_eq!(decoded.mint_b, escrow.mint_b);
+        assert_eq!(decoded_receive, receive);
+        assert_eq!(decoded_deposited, deposited);
+        assert_eq!(decoded_filled, filled);
+        assert_eq!(decoded_expiry, expiry);
+        assert_eq!(decoded.bump, escrow.bump);
+    }
 }